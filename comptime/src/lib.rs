@@ -1,17 +1,200 @@
 use num_bigint::{BigUint, ToBigUint};
 use num_traits::{Num};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 use std::default::Default;
 use std::ops::{Add, Mul, Sub};
 use std::str::FromStr;
 
 pub type FieldElement = BigUint;
 
+/// Width, in bytes, of the canonical big-endian encoding used to (de)serialize
+/// a [`FieldElement`]. 32 bytes covers every field this crate targets (BN254,
+/// BLS12-381, Pallas/Vesta, Goldilocks) with room to spare.
+const FIELD_ELEMENT_BYTE_WIDTH: usize = 32;
+
+/// Serializes [`FieldElement`]s as fixed-width big-endian hex so that
+/// round-tripping a `SparseArray` through JSON/bincode reproduces the exact
+/// value, rather than relying on `BigUint`'s own (sign, little-endian digits)
+/// representation.
+mod field_hex_serde {
+    use super::{FieldElement, FIELD_ELEMENT_BYTE_WIDTH};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    fn to_hex(value: &FieldElement) -> String {
+        let bytes = value.to_bytes_be();
+        assert!(
+            bytes.len() <= FIELD_ELEMENT_BYTE_WIDTH,
+            "field element does not fit in {} bytes",
+            FIELD_ELEMENT_BYTE_WIDTH
+        );
+        let mut padded = [0u8; FIELD_ELEMENT_BYTE_WIDTH];
+        let start = FIELD_ELEMENT_BYTE_WIDTH - bytes.len();
+        padded[start..].copy_from_slice(&bytes);
+        let mut hex = String::with_capacity(2 + FIELD_ELEMENT_BYTE_WIDTH * 2);
+        hex.push_str("0x");
+        for byte in padded {
+            hex.push_str(&format!("{:02x}", byte));
+        }
+        hex
+    }
+
+    fn from_hex<E: serde::de::Error>(hex: &str) -> Result<FieldElement, E> {
+        let digits = hex.strip_prefix("0x").unwrap_or(hex);
+        if !digits.len().is_multiple_of(2) {
+            return Err(E::custom("hex-encoded field element must have an even number of digits"));
+        }
+        let mut bytes = Vec::with_capacity(digits.len() / 2);
+        for i in (0..digits.len()).step_by(2) {
+            let byte = u8::from_str_radix(&digits[i..i + 2], 16).map_err(E::custom)?;
+            bytes.push(byte);
+        }
+        Ok(FieldElement::from_bytes_be(&bytes))
+    }
+
+    pub fn serialize<S: Serializer>(value: &FieldElement, serializer: S) -> Result<S::Ok, S::Error> {
+        to_hex(value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<FieldElement, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        from_hex(&hex)
+    }
+
+    pub mod vec {
+        use super::{from_hex, to_hex};
+        use crate::FieldElement;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            values: &[FieldElement],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            values
+                .iter()
+                .map(to_hex)
+                .collect::<Vec<_>>()
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<FieldElement>, D::Error> {
+            Vec::<String>::deserialize(deserializer)?
+                .iter()
+                .map(|hex| from_hex(hex))
+                .collect()
+        }
+    }
+
+    /// Same encoding as [`vec`], for fixed-size `[FieldElement; 3]` fields
+    /// (e.g. struct-valued tables whose entries carry a few field elements).
+    pub mod array3 {
+        use super::{from_hex, to_hex};
+        use crate::FieldElement;
+        use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            values: &[FieldElement; 3],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let hex: Vec<String> = values.iter().map(to_hex).collect();
+            hex.serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<[FieldElement; 3], D::Error> {
+            let hex = Vec::<String>::deserialize(deserializer)?;
+            let fields: Vec<FieldElement> = hex.iter().map(|h| from_hex(h)).collect::<Result<_, _>>()?;
+            fields
+                .try_into()
+                .map_err(|_| D::Error::custom("expected exactly 3 field elements"))
+        }
+    }
+}
+
 lazy_static::lazy_static! {
     static ref FIELD_MODULUS: FieldElement = FieldElement::from_str(
         "21888242871839275222246405745257275088696311157297823662689037894645226208583"
     ).unwrap();
 }
 
+/// Describes the field modulus a `SparseArray` table is generated against.
+///
+/// `create`/`create_packed` default to [`FieldParams::bn254`] (the
+/// `FIELD_MODULUS` constant), but other proving backends use other curves, so the modulus used
+/// for the "exceeds field modulus" boundary checks can be swapped out via
+/// `create_with_field_params`/`create_packed_with_field_params`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldParams {
+    pub name: String,
+    pub modulus: FieldElement,
+}
+
+impl FieldParams {
+    pub fn new(name: &str, modulus: FieldElement) -> Self {
+        FieldParams {
+            name: name.to_string(),
+            modulus,
+        }
+    }
+
+    /// The BN254 modulus held in `FIELD_MODULUS`, used by default. This is the
+    /// curve's base-field prime `q`, not the scalar-field order `r` of Noir's
+    /// native `Field` type.
+    pub fn bn254() -> Self {
+        FieldParams::new("bn254", FIELD_MODULUS.clone())
+    }
+
+    /// The BLS12-381 scalar field.
+    pub fn bls12_381() -> Self {
+        FieldParams::new(
+            "bls12_381",
+            FieldElement::from_str(
+                "52435875175126190479447740508185965837690552500527637822603658699938581184513",
+            )
+            .unwrap(),
+        )
+    }
+
+    /// The Pallas scalar field (used by the Vesta curve's base field, and vice versa).
+    pub fn pallas() -> Self {
+        FieldParams::new(
+            "pallas",
+            FieldElement::from_str(
+                "28948022309329048855892746252171976963363056481941647379679742748393362948097",
+            )
+            .unwrap(),
+        )
+    }
+
+    /// The Vesta scalar field.
+    pub fn vesta() -> Self {
+        FieldParams::new(
+            "vesta",
+            FieldElement::from_str(
+                "28948022309329048855892746252171976963363056481941560715954676764349967630337",
+            )
+            .unwrap(),
+        )
+    }
+
+    /// The Goldilocks field, `2^64 - 2^32 + 1`.
+    pub fn goldilocks() -> Self {
+        FieldParams::new(
+            "goldilocks",
+            FieldElement::from_str("18446744069414584321").unwrap(),
+        )
+    }
+}
+
+impl Default for FieldParams {
+    fn default() -> Self {
+        FieldParams::bn254()
+    }
+}
+
 pub trait ToU32 {
     fn to_u32(&self) -> u32;
 }
@@ -19,23 +202,41 @@ pub trait ToU32 {
 // Implement for BigUint
 impl ToU32 for BigUint {
     fn to_u32(&self) -> u32 {
-        self.to_u32_digits()[0]
+        // `to_u32_digits` returns an empty vec for zero, rather than `[0]`.
+        self.to_u32_digits().first().copied().unwrap_or(0)
     }
 }
 
+/// Controls how [`SparseArray::to_noir_string_with_format`] renders keys,
+/// values, and the maximum as Noir literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoirValueFormat {
+    /// Historical behaviour: render as a hex literal zero-padded to 8 nibbles.
+    /// Keys and the maximum wider than 32 bits are printed in full; panics if
+    /// an entry of `values` doesn't fit in 32 bits.
+    #[default]
+    U32,
+    /// Render the full 256-bit value as a zero-padded 64-nibble hex literal,
+    /// matching Noir's native `Field` representation.
+    Field,
+}
+
 #[derive(Debug)]
 pub struct SortResult {
     pub sorted: Vec<FieldElement>,
     pub sort_indices: Vec<usize>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
 pub struct SparseArray<T>
 where
     T: std::fmt::Debug,
 {
+    #[serde(with = "field_hex_serde::vec")]
     keys: Vec<FieldElement>,
     values: Vec<T>,
+    #[serde(with = "field_hex_serde")]
     maximum: FieldElement,
 }
 
@@ -53,6 +254,15 @@ where
         + PartialOrd,
 {
     pub fn create(keys: &[FieldElement], values: &[T], size: FieldElement) -> Self {
+        Self::create_with_field_params(keys, values, size, &FieldParams::default())
+    }
+
+    pub fn create_with_field_params(
+        keys: &[FieldElement],
+        values: &[T],
+        size: FieldElement,
+        field_params: &FieldParams,
+    ) -> Self {
         let n = keys.len();
         println!("Key length: {}", n);
         assert_eq!(n, values.len(), "Keys and values must have the same length");
@@ -100,16 +310,27 @@ where
 
         // Boundary checks
         assert!(
-            &sorted.sorted[0] < &*FIELD_MODULUS,
+            &sorted.sorted[0] < &field_params.modulus,
             "Key exceeds field modulus"
         );
-        assert!(&maximum < &*FIELD_MODULUS, "Maximum exceeds field modulus");
+        assert!(
+            &maximum < &field_params.modulus,
+            "Maximum exceeds field modulus"
+        );
         assert!(&maximum >= &sorted.sorted[n - 1], "Key exceeds maximum");
 
         result
     }
 
     pub fn create_packed(table: &[T], max_size: u32) -> Self {
+        Self::create_packed_with_field_params(table, max_size, &FieldParams::default())
+    }
+
+    pub fn create_packed_with_field_params(
+        table: &[T],
+        max_size: u32,
+        field_params: &FieldParams,
+    ) -> Self {
         let mut small_keys = Vec::new();
         let mut small_values = Vec::new();
         let mut keys = Vec::new();
@@ -134,7 +355,17 @@ where
             }
         }
 
-        // Combine values according to the dual encoding scheme
+        // Combine values according to the dual encoding scheme. Index the
+        // existing keys by value so the "find existing key" step is O(1)
+        // instead of a linear scan, turning the merge into roughly
+        // O(small_keys * max_value).
+        let mut key_index: HashMap<FieldElement, usize> = keys
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(index, key)| (key, index))
+            .collect();
+
         for i in 0..small_keys.len() {
             let target_value = small_values[i].clone() * T::from(256);
 
@@ -142,17 +373,11 @@ where
             for j in 0..max_value.to_u32() {
                 let target_key =
                     FieldElement::from(j) * FieldElement::from(256u32) + small_keys[i].clone();
-                let mut found_key = false;
-
-                for k in 0..keys.len() {
-                    if keys[k] == target_key {
-                        values[k] = values[k].clone() + target_value.clone();
-                        found_key = true;
-                        break;
-                    }
-                }
 
-                if !found_key {
+                if let Some(&k) = key_index.get(&target_key) {
+                    values[k] = values[k].clone() + target_value.clone();
+                } else {
+                    key_index.insert(target_key.clone(), keys.len());
                     keys.push(target_key);
                     values.push(target_value.clone());
                 }
@@ -164,24 +389,55 @@ where
         println!("Number of entries: {}", num_entries);
 
         // Create the SparseArray using the create method
-        Self::create(&keys, &values, FieldElement::from(max_size))
+        Self::create_with_field_params(&keys, &values, FieldElement::from(max_size), field_params)
     }
 
     pub fn to_noir_string(&self, generic_name: Option<&str>) -> String
     where
         T: ToString,
     {
+        self.to_noir_string_with_format(generic_name, NoirValueFormat::default())
+    }
+
+    /// Like [`to_noir_string`](Self::to_noir_string), but lets the caller pick
+    /// how keys/values/maximum are rendered. [`NoirValueFormat::U32`] matches
+    /// the historical output (8-nibble hex literals, panicking on values wider
+    /// than 32 bits), while [`NoirValueFormat::Field`] renders the full
+    /// 256-bit value as a zero-padded 64-nibble hex literal, so tables with
+    /// field-sized values can be rendered.
+    pub fn to_noir_string_with_format(
+        &self,
+        generic_name: Option<&str>,
+        format: NoirValueFormat,
+    ) -> String
+    where
+        T: ToString,
+    {
+        let format_field = |f: &FieldElement| match format {
+            NoirValueFormat::U32 => format!("0x{:08x}", f),
+            NoirValueFormat::Field => format!("0x{:064x}", f),
+        };
+
+        let format_value = |v: &T| match format {
+            NoirValueFormat::U32 => format!("0x{:08x}", v.to_string().parse::<u32>().unwrap()),
+            NoirValueFormat::Field => {
+                let value = FieldElement::from_str(&v.to_string())
+                    .expect("value must be representable as a field element");
+                format!("0x{:064x}", value)
+            }
+        };
+
         let keys_str = self
             .keys
             .iter()
-            .map(|k| format!("0x{:08x}", k))
+            .map(format_field)
             .collect::<Vec<_>>()
             .join(", ");
 
         let values_str = self
             .values
             .iter()
-            .map(|v| format!("0x{:08x}", v.to_string().parse::<u32>().unwrap()))
+            .map(format_value)
             .collect::<Vec<_>>()
             .join(", ");
 
@@ -192,9 +448,13 @@ where
             "SparseArray<{}, {}> = SparseArray {{\n    \
              keys: [{}],\n    \
              values: [{}],\n    \
-             maximum: 0x{:08x}\n\
+             maximum: {}\n\
              }};",
-            table_length, generic_name, keys_str, values_str, self.maximum
+            table_length,
+            generic_name,
+            keys_str,
+            values_str,
+            format_field(&self.maximum)
         )
     }
 
@@ -229,6 +489,127 @@ where
     pub fn get_maximum(&self) -> &FieldElement {
         &self.maximum
     }
+
+    /// Adds a new `key` -> `value` mapping, or overwrites it if `key` is
+    /// already present. Binary-searches the sorted `keys` for the insertion
+    /// point and splices both `keys` and the offset-by-one `values` so the
+    /// invariant `get` relies on (sorted keys, `values[i + 1]` holding the
+    /// value for `keys[i]`) keeps holding.
+    ///
+    /// `key == maximum` is handled separately: `get`'s binary search never
+    /// inspects the very last entry of `keys`, so a real value there has to
+    /// live in a reachable duplicate spliced in just before the trailing
+    /// sentinel -- the same trick `create` relies on when `maximum` is
+    /// itself one of the input keys.
+    pub fn insert(&mut self, key: &FieldElement, value: T) {
+        assert!(key <= &self.maximum, "Key exceeds maximum");
+
+        if key == &self.maximum {
+            let last = self.keys.len() - 1;
+            if self.keys[last - 1] == self.maximum {
+                self.values[last] = value;
+            } else {
+                self.keys.insert(last, self.maximum.clone());
+                self.values.insert(last + 1, value);
+            }
+            return;
+        }
+
+        // `key == 0` is handled separately too: `keys[0]` is always 0, be it
+        // the leading sentinel or a real key, so `binary_search` can resolve
+        // a lookup for it to either `keys[0]` or a real duplicate at
+        // `keys[1]` depending on tie-breaking it doesn't promise to keep
+        // stable. Maintain the duplicate explicitly instead, the same way
+        // `create` does, and keep both slots in sync so either resolution
+        // `get` might take is correct.
+        if key == &FieldElement::from(0u32) {
+            if self.keys[1] == FieldElement::from(0u32) {
+                self.values[1] = value.clone();
+                self.values[2] = value;
+            } else {
+                self.keys.insert(1, FieldElement::from(0u32));
+                self.values.insert(2, value.clone());
+                self.values[1] = value;
+            }
+            return;
+        }
+
+        match self.keys.binary_search(key) {
+            Ok(existing) => self.values[existing + 1] = value,
+            Err(insert_at) => {
+                self.keys.insert(insert_at, key.clone());
+                self.values.insert(insert_at + 1, value);
+            }
+        }
+    }
+
+    /// Overwrites the value for an already-present `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not present; use [`insert`](Self::insert) to add it first.
+    pub fn update(&mut self, key: &FieldElement, value: T) {
+        if key == &self.maximum {
+            let last = self.keys.len() - 1;
+            if self.keys[last - 1] == self.maximum {
+                self.values[last] = value;
+                return;
+            }
+            panic!("Cannot update a key that is not present in the sparse array");
+        }
+
+        // See the matching comment in `insert`: a real key-0 entry shows up
+        // as a duplicate at `keys[1]`, which is the only reliable way to
+        // tell "0 is a genuine key" apart from "0 is just the sentinel".
+        if key == &FieldElement::from(0u32) {
+            if self.keys[1] == FieldElement::from(0u32) {
+                self.values[1] = value.clone();
+                self.values[2] = value;
+                return;
+            }
+            panic!("Cannot update a key that is not present in the sparse array");
+        }
+
+        match self.keys.binary_search(key) {
+            Ok(existing) => self.values[existing + 1] = value,
+            Err(_) => panic!("Cannot update a key that is not present in the sparse array"),
+        }
+    }
+
+    /// Removes `key`'s mapping, collapsing its interval back to the default
+    /// value. A no-op if `key` isn't present.
+    pub fn remove(&mut self, key: &FieldElement) {
+        if key == &self.maximum {
+            let last = self.keys.len() - 1;
+            if self.keys[last - 1] == self.maximum {
+                self.keys.remove(last - 1);
+                self.values.remove(last);
+            }
+            return;
+        }
+
+        // `keys[0]` (the leading 0 sentinel) is never removed outright, since
+        // `get`'s binary search always starts from it. Rather than let
+        // `binary_search` pick between it and a real duplicate at `keys[1]`
+        // (see `insert`), collapse the duplicate explicitly if one exists,
+        // then always reset the now-sole slot to the default.
+        if key == &FieldElement::from(0u32) {
+            if self.keys[1] == FieldElement::from(0u32) {
+                self.keys.remove(1);
+                self.values.remove(2);
+            }
+            self.values[1] = T::default();
+            return;
+        }
+
+        let existing = match self.keys.binary_search(key) {
+            Ok(existing) => existing,
+            Err(_) => return,
+        };
+
+        self.keys.remove(existing);
+        self.values.remove(existing + 1);
+    }
 }
 
 fn sort_advanced(input: &[FieldElement]) -> SortResult {
@@ -250,19 +631,24 @@ fn sort_advanced(input: &[FieldElement]) -> SortResult {
 
 fn get_shuffle_indices(lhs: &[FieldElement], rhs: &[FieldElement]) -> Vec<usize> {
     let n = lhs.len();
+
+    // Map each value in the sorted output to the (ascending) positions it
+    // occupies there, so that duplicate keys are consumed in stable,
+    // first-come order below. O(n) instead of the old O(n^2) nested scan.
+    let mut positions_by_value: HashMap<FieldElement, VecDeque<usize>> = HashMap::new();
+    for (j, value) in rhs.iter().enumerate() {
+        positions_by_value
+            .entry(value.clone())
+            .or_default()
+            .push_back(j);
+    }
+
     let mut shuffle_indices = vec![0usize; n];
-    let mut shuffle_mask = vec![false; n];
-
-    for i in 0..n {
-        let mut found = false;
-        for j in 0..n {
-            if !shuffle_mask[j] && !found && lhs[i] == rhs[j] {
-                found = true;
-                shuffle_indices[i] = j;
-                shuffle_mask[j] = true;
-            }
-        }
-        assert!(found, "Arrays do not contain equivalent values");
+    for (i, value) in lhs.iter().enumerate() {
+        let j = positions_by_value
+            .get_mut(value)
+            .and_then(|positions| positions.pop_front());
+        shuffle_indices[i] = j.expect("Arrays do not contain equivalent values");
     }
 
     shuffle_indices
@@ -322,6 +708,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_field_params_pallas_and_vesta_are_not_swapped() {
+        // Pallas's scalar field is Vesta's base field, and vice versa; make
+        // sure `pallas()`/`vesta()` report their own *scalar* field, not the
+        // other curve's.
+        assert_eq!(
+            FieldParams::pallas().modulus,
+            field("28948022309329048855892746252171976963363056481941647379679742748393362948097"),
+        );
+        assert_eq!(
+            FieldParams::vesta().modulus,
+            field("28948022309329048855892746252171976963363056481941560715954676764349967630337"),
+        );
+        assert_ne!(FieldParams::pallas().modulus, FieldParams::vesta().modulus);
+    }
+
     #[test]
     fn test_sparse_lookup() {
         let keys = vec![field("1"), field("99"), field("7"), field("5")];
@@ -377,6 +779,81 @@ mod tests {
         assert_eq!(*example.get(&field("4294967294")), 0); // 0xfffffffe
     }
 
+    #[test]
+    fn test_insert_update_remove_matches_fresh_create() {
+        let keys = vec![field("10"), field("20"), field("30")];
+        let values = vec![field("100"), field("200"), field("300")];
+        let mut example = SparseArray::create(&keys, &values, field("1000"));
+
+        example.insert(&field("15"), field("150"));
+        example.update(&field("20"), field("222"));
+        example.remove(&field("30"));
+        example.insert(&field("40"), field("400"));
+
+        let expected_keys = vec![field("10"), field("15"), field("20"), field("40")];
+        let expected_values = vec![field("100"), field("150"), field("222"), field("400")];
+        let expected = SparseArray::create(&expected_keys, &expected_values, field("1000"));
+
+        for i in 0u32..1000 {
+            let i_field = FieldElement::from(i);
+            assert_eq!(example.get(&i_field), expected.get(&i_field));
+        }
+    }
+
+    #[test]
+    fn test_insert_and_remove_boundary_keys() {
+        let keys = vec![field("10"), field("20")];
+        let values = vec![field("100"), field("200")];
+        let mut example = SparseArray::create(&keys, &values, field("100"));
+
+        example.insert(&field("0"), field("1"));
+        example.insert(&field("99"), field("2")); // maximum = 100 - 1 = 99
+        assert_eq!(*example.get(&field("0")), field("1"));
+        assert_eq!(*example.get(&field("99")), field("2"));
+
+        example.remove(&field("0"));
+        example.remove(&field("99"));
+        assert_eq!(*example.get(&field("0")), field("0"));
+        assert_eq!(*example.get(&field("99")), field("0"));
+
+        // Interior keys are unaffected by boundary mutation.
+        assert_eq!(*example.get(&field("10")), field("100"));
+        assert_eq!(*example.get(&field("20")), field("200"));
+    }
+
+    #[test]
+    fn test_remove_real_zero_key_from_create() {
+        // Unlike `test_insert_and_remove_boundary_keys`, key 0 is a genuine
+        // entry from `create` here, not one added later via `insert`. It
+        // shares a slot with the leading sentinel, so removing it must not
+        // be confused with just clearing the sentinel's (already-default)
+        // value.
+        let keys = vec![field("0"), field("20")];
+        let values = vec![field("100"), field("200")];
+        let mut example = SparseArray::create(&keys, &values, field("100"));
+        assert_eq!(*example.get(&field("0")), field("100"));
+
+        example.remove(&field("0"));
+        assert_eq!(*example.get(&field("0")), field("0"));
+
+        // The other entry survives the removal untouched.
+        assert_eq!(*example.get(&field("20")), field("200"));
+
+        // The slot is free again: a fresh value can be inserted at key 0.
+        example.insert(&field("0"), field("777"));
+        assert_eq!(*example.get(&field("0")), field("777"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot update a key that is not present in the sparse array")]
+    fn test_update_missing_key_panics() {
+        let keys = vec![field("10"), field("20")];
+        let values = vec![field("100"), field("200")];
+        let mut example = SparseArray::create(&keys, &values, field("100"));
+
+        example.update(&field("15"), field("999"));
+    }
+
     #[test]
     #[should_panic(expected = "Maximum exceeds field modulus")]
     fn test_sparse_lookup_overflow() {
@@ -406,8 +883,9 @@ mod tests {
         );
     }
 
-    #[derive(Debug, Clone, PartialEq)]
+    #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
     struct F {
+        #[serde(with = "field_hex_serde::array3")]
         foo: [FieldElement; 3],
     }
 
@@ -419,6 +897,47 @@ mod tests {
         }
     }
 
+    impl From<u32> for F {
+        fn from(value: u32) -> Self {
+            F {
+                foo: [FieldElement::from(value), FieldElement::from(0u32), FieldElement::from(0u32)],
+            }
+        }
+    }
+
+    impl ToU32 for F {
+        fn to_u32(&self) -> u32 {
+            self.foo[0].to_u32()
+        }
+    }
+
+    impl Add for F {
+        type Output = F;
+        fn add(self, other: F) -> F {
+            F {
+                foo: std::array::from_fn(|i| self.foo[i].clone() + other.foo[i].clone()),
+            }
+        }
+    }
+
+    impl Sub for F {
+        type Output = F;
+        fn sub(self, other: F) -> F {
+            F {
+                foo: std::array::from_fn(|i| self.foo[i].clone() - other.foo[i].clone()),
+            }
+        }
+    }
+
+    impl Mul for F {
+        type Output = F;
+        fn mul(self, other: F) -> F {
+            F {
+                foo: std::array::from_fn(|i| self.foo[i].clone() * other.foo[i].clone()),
+            }
+        }
+    }
+
     #[test]
     fn test_sparse_lookup_struct() {
         let values = vec![
@@ -455,6 +974,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sparse_array_serde_roundtrip_json() {
+        let keys = vec![field("1"), field("99"), field("7"), field("5")];
+        let values = vec![
+            F { foo: [field("1"), field("2"), field("3")] },
+            F { foo: [field("4"), field("5"), field("6")] },
+            F { foo: [field("7"), field("8"), field("9")] },
+            F { foo: [field("10"), field("11"), field("12")] },
+        ];
+        let example = SparseArray::create(&keys, &values, field("100"));
+
+        let json = serde_json::to_string(&example).unwrap();
+        let roundtripped: SparseArray<F> = serde_json::from_str(&json).unwrap();
+
+        for i in 0u32..100 {
+            let i_field = FieldElement::from(i);
+            assert_eq!(example.get(&i_field), roundtripped.get(&i_field));
+        }
+    }
+
+    #[test]
+    fn test_sparse_array_serde_roundtrip_struct_values() {
+        let values = vec![
+            F {
+                foo: [field("1"), field("2"), field("3")],
+            },
+            F {
+                foo: [field("4"), field("5"), field("6")],
+            },
+            F {
+                foo: [field("7"), field("8"), field("9")],
+            },
+            F {
+                foo: [field("10"), field("11"), field("12")],
+            },
+        ];
+        let keys = vec![field("1"), field("99"), field("7"), field("5")];
+        let example = SparseArray::create(&keys, &values, field("100000"));
+
+        let bytes = bincode::serialize(&example).unwrap();
+        let roundtripped: SparseArray<F> = bincode::deserialize(&bytes).unwrap();
+
+        for i in 0u32..100 {
+            let i_field = FieldElement::from(i);
+            assert_eq!(example.get(&i_field), roundtripped.get(&i_field));
+        }
+    }
+
     #[test]
     fn test_sparse_array_noir_representation() {
         let keys = vec![
@@ -482,6 +1049,30 @@ mod tests {
         assert_eq!(noir_str, expected);
     }
 
+    #[test]
+    fn test_sparse_array_noir_representation_field_format() {
+        let keys = vec![field("0"), field("99999"), field("7")];
+        let values = vec![
+            field("0"),
+            field("101112"),
+            field(
+                "21888242871839275222246405745257275088696311157297823662689037894645226208582",
+            ),
+        ];
+        let example = SparseArray::create(&keys, &values, field("100000"));
+
+        let noir_str = example.to_noir_string_with_format(None, NoirValueFormat::Field);
+
+        // Zero must be rendered as a full-width 64-nibble literal, and the
+        // 254-bit value must be rendered in full rather than clipped.
+        assert!(noir_str.contains(
+            "0x0000000000000000000000000000000000000000000000000000000000000000"
+        ));
+        assert!(noir_str.contains(
+            "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46"
+        ));
+    }
+
     // Test cases for console output
     // #[test]
     // fn print_sparse_array_10_random() {